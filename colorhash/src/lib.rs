@@ -3,24 +3,282 @@
 //
 pub use colorsys::{Hsl, Rgb};
 use {
-    byteorder::{BigEndian, ReadBytesExt},
     hmac_sha256::Hash,
-    std::{io::Cursor, ops::Range},
+    std::{ops::Range, sync::Arc},
 };
 
-fn rgb_hash(key: &str) -> usize {
-    Cursor::new(Hash::hash(key.as_bytes()))
-        .read_u32::<BigEndian>()
-        .expect("Hash is too small") // always succeds for sha256!
-         as usize
+/// A pluggable hash backend.
+///
+/// The default is [`Sha256Hasher`], but you can supply a faster
+/// non-cryptographic hash (or namespace your color space) via
+/// [`ColorHash::hasher`] without forking the crate.
+pub trait Hasher: std::fmt::Debug {
+    /// Return the raw digest bytes for `input`. At least 20 bytes are
+    /// recommended so each color dimension (including alpha) gets an
+    /// independent lane; shorter digests are read with wrap-around.
+    fn digest(&self, input: &str) -> Vec<u8>;
 }
 
-/// Convert a string to its color representation using a hash function.
+/// The default SHA256 hash backend.
 #[derive(Clone, Debug, PartialEq)]
+pub struct Sha256Hasher;
+
+impl Hasher for Sha256Hasher {
+    fn digest(&self, input: &str) -> Vec<u8> {
+        Hash::hash(input.as_bytes()).to_vec()
+    }
+}
+
+/// Read the `index`-th big-endian `u32` lane from a digest, wrapping around
+/// when the digest is shorter than the requested lane.
+fn lane(digest: &[u8], index: usize) -> usize {
+    if digest.is_empty() {
+        return 0;
+    }
+    let mut value = 0u32;
+    for i in 0..4 {
+        value = (value << 8) | digest[(index * 4 + i) % digest.len()] as u32;
+    }
+    value as usize
+}
+
+/// A named hue family.
+///
+/// Each family maps to a hue range and a polygon of valid saturation/brightness
+/// pairs, mirroring the well-known RandomColor dictionary. Use it with
+/// [`ColorHash::color_family`] to request human-meaningful colors instead of
+/// hand-specifying hue ranges and saturation/lightness vectors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Blue,
+    Purple,
+    Pink,
+    Monochrome,
+}
+
+/// A luminosity preset that clamps the generated brightness into a sub-band.
+///
+/// Use it with [`ColorHash::luminosity`]. `Random` leaves the family's full
+/// brightness range untouched.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Luminosity {
+    Bright,
+    Light,
+    Dark,
+    Random,
+}
+
+impl Color {
+    /// The hue range of the family in degrees. The start may be negative for
+    /// families that straddle 0° (red); callers normalise into `[0, 360)`.
+    fn hue_range(&self) -> (f64, f64) {
+        match self {
+            Color::Red => (-26.0, 18.0),
+            Color::Orange => (19.0, 46.0),
+            Color::Yellow => (47.0, 62.0),
+            Color::Green => (63.0, 178.0),
+            Color::Blue => (179.0, 257.0),
+            Color::Purple => (258.0, 282.0),
+            Color::Pink => (283.0, 334.0),
+            Color::Monochrome => (0.0, 0.0),
+        }
+    }
+
+    /// The lower-bound `(saturation, brightness)` points defining the bottom
+    /// edge of the family's valid S/B polygon.
+    fn lower_bounds(&self) -> &'static [(f64, f64)] {
+        match self {
+            Color::Red => &[
+                (20., 100.),
+                (30., 92.),
+                (40., 89.),
+                (50., 85.),
+                (60., 78.),
+                (70., 70.),
+                (80., 60.),
+                (90., 55.),
+                (100., 50.),
+            ],
+            Color::Orange => &[
+                (20., 100.),
+                (30., 93.),
+                (40., 88.),
+                (50., 86.),
+                (60., 85.),
+                (70., 70.),
+                (100., 70.),
+            ],
+            Color::Yellow => &[
+                (25., 100.),
+                (40., 94.),
+                (50., 89.),
+                (60., 86.),
+                (70., 84.),
+                (80., 82.),
+                (90., 80.),
+                (100., 75.),
+            ],
+            Color::Green => &[
+                (30., 100.),
+                (40., 90.),
+                (50., 85.),
+                (60., 81.),
+                (70., 74.),
+                (80., 64.),
+                (90., 50.),
+                (100., 40.),
+            ],
+            Color::Blue => &[
+                (20., 100.),
+                (30., 86.),
+                (40., 80.),
+                (50., 74.),
+                (60., 60.),
+                (70., 52.),
+                (80., 44.),
+                (90., 39.),
+                (100., 35.),
+            ],
+            Color::Purple => &[
+                (20., 100.),
+                (30., 87.),
+                (40., 79.),
+                (50., 70.),
+                (60., 65.),
+                (70., 59.),
+                (80., 52.),
+                (90., 45.),
+                (100., 42.),
+            ],
+            Color::Pink => &[
+                (20., 100.),
+                (30., 90.),
+                (40., 86.),
+                (60., 84.),
+                (80., 80.),
+                (90., 75.),
+                (100., 73.),
+            ],
+            Color::Monochrome => &[(0., 0.), (100., 0.)],
+        }
+    }
+
+    /// The `[min, max]` saturation of the family, taken from the first and last
+    /// lower-bound points.
+    fn saturation_range(&self) -> (f64, f64) {
+        let bounds = self.lower_bounds();
+        (bounds[0].0, bounds[bounds.len() - 1].0)
+    }
+
+    /// The minimum brightness for a given saturation, linearly interpolated
+    /// between the two bracketing lower-bound points.
+    fn minimum_brightness(&self, saturation: f64) -> f64 {
+        let bounds = self.lower_bounds();
+        for pair in bounds.windows(2) {
+            let (s1, b1) = pair[0];
+            let (s2, b2) = pair[1];
+            if saturation >= s1 && saturation <= s2 {
+                if s2 == s1 {
+                    return b1;
+                }
+                let t = (saturation - s1) / (s2 - s1);
+                return b1 + t * (b2 - b1);
+            }
+        }
+        0.0
+    }
+}
+
+/// Convert an HSB (a.k.a. HSV) triple, with S and B in `[0, 100]`, into an
+/// [`Hsl`]. The color dictionary is expressed in HSB while the crate emits HSL.
+fn hsb_to_hsl(hue: f64, saturation: f64, brightness: f64) -> Hsl {
+    let sv = saturation / 100.0;
+    let v = brightness / 100.0;
+    let l = v * (1.0 - sv / 2.0);
+    let s = if l == 0.0 || l == 1.0 {
+        0.0
+    } else {
+        (v - l) / l.min(1.0 - l)
+    };
+    Hsl::new(hue, s * 100.0, l * 100.0, None)
+}
+
+/// The WCAG relative luminance of an sRGB color, in `[0, 1]`.
+fn relative_luminance(rgb: &Rgb) -> f64 {
+    fn linear(channel: f64) -> f64 {
+        let c = channel / 255.0;
+        if c <= 0.03928 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    }
+    0.2126 * linear(rgb.red()) + 0.7152 * linear(rgb.green()) + 0.0722 * linear(rgb.blue())
+}
+
+/// The WCAG contrast ratio between two relative luminances.
+fn contrast_ratio(l1: f64, l2: f64) -> f64 {
+    (l1.max(l2) + 0.05) / (l1.min(l2) + 0.05)
+}
+
+/// Normalise a hue into `[0, 360)` following the CSS serialization rule.
+fn normalize_hue(hue: f64) -> f64 {
+    hue - 360.0 * (hue / 360.0).floor()
+}
+
+/// Format an alpha value for CSS serialization.
+///
+/// Alpha is rounded to two decimal places, falling back to three only when the
+/// two-decimal value does not round-trip to the same `0..=255` byte.
+fn format_alpha(alpha: f64) -> String {
+    let byte = (alpha * 255.0).round();
+    let two = (alpha * 100.0).round() / 100.0;
+    if ((two * 255.0).round() - byte).abs() < f64::EPSILON {
+        format!("{}", two)
+    } else {
+        format!("{}", (alpha * 1000.0).round() / 1000.0)
+    }
+}
+
+/// Convert a string to its color representation using a hash function.
+///
+/// # Equality
+///
+/// [`PartialEq`] compares the color-space configuration only; the pluggable
+/// [`Hasher`] backend is a trait object and is **not** part of the comparison.
+/// Two `ColorHash` values that differ only in their hasher (and so produce
+/// different colors for the same input) therefore compare equal — keep this in
+/// mind when using `ColorHash` as a set/dedup key.
+#[derive(Clone, Debug)]
 pub struct ColorHash {
     s: Vec<f64>,
     l: Vec<f64>,
     hue_ranges: Vec<Range<f64>>,
+    color_family: Option<Color>,
+    luminosity: Option<Luminosity>,
+    contrast: Option<(Rgb, f64)>,
+    hasher: Arc<dyn Hasher>,
+    salt: Option<String>,
+    alpha_range: Option<Range<f64>>,
+}
+
+// Two color spaces are equal when their configuration fields match; the hash
+// backend is a trait object and is left out of the comparison.
+impl PartialEq for ColorHash {
+    fn eq(&self, other: &Self) -> bool {
+        self.s == other.s
+            && self.l == other.l
+            && self.hue_ranges == other.hue_ranges
+            && self.color_family == other.color_family
+            && self.luminosity == other.luminosity
+            && self.contrast == other.contrast
+            && self.salt == other.salt
+            && self.alpha_range == other.alpha_range
+    }
 }
 
 impl Default for ColorHash {
@@ -32,6 +290,12 @@ impl Default for ColorHash {
             s: vec![35., 50., 65.], // note that length 3 is a prime
             l: vec![35., 50., 65.], // note that length 3 is a prime
             hue_ranges: vec![],
+            color_family: None,
+            luminosity: None,
+            contrast: None,
+            hasher: Arc::new(Sha256Hasher),
+            salt: None,
+            alpha_range: None,
         }
     }
 }
@@ -56,6 +320,12 @@ impl ColorHash {
             s: self.s,
             l: vec![lightness],
             hue_ranges: self.hue_ranges,
+            color_family: self.color_family,
+            luminosity: self.luminosity,
+            contrast: self.contrast,
+            hasher: self.hasher,
+            salt: self.salt,
+            alpha_range: self.alpha_range,
         }
     }
 
@@ -70,6 +340,12 @@ impl ColorHash {
             s: self.s,
             l: lightness.to_owned(),
             hue_ranges: self.hue_ranges,
+            color_family: self.color_family,
+            luminosity: self.luminosity,
+            contrast: self.contrast,
+            hasher: self.hasher,
+            salt: self.salt,
+            alpha_range: self.alpha_range,
         }
     }
 
@@ -83,6 +359,12 @@ impl ColorHash {
             s: vec![saturation],
             l: self.l,
             hue_ranges: self.hue_ranges,
+            color_family: self.color_family,
+            luminosity: self.luminosity,
+            contrast: self.contrast,
+            hasher: self.hasher,
+            salt: self.salt,
+            alpha_range: self.alpha_range,
         }
     }
 
@@ -97,6 +379,12 @@ impl ColorHash {
             s: saturation.to_owned(),
             l: self.l,
             hue_ranges: self.hue_ranges,
+            color_family: self.color_family,
+            luminosity: self.luminosity,
+            contrast: self.contrast,
+            hasher: self.hasher,
+            salt: self.salt,
+            alpha_range: self.alpha_range,
         }
     }
 
@@ -111,6 +399,12 @@ impl ColorHash {
             s: self.s,
             l: self.l,
             hue_ranges: vec![hue_range],
+            color_family: self.color_family,
+            luminosity: self.luminosity,
+            contrast: self.contrast,
+            hasher: self.hasher,
+            salt: self.salt,
+            alpha_range: self.alpha_range,
         }
     }
 
@@ -126,6 +420,155 @@ impl ColorHash {
             s: self.s,
             l: self.l,
             hue_ranges: hue_ranges.to_owned(),
+            color_family: self.color_family,
+            luminosity: self.luminosity,
+            contrast: self.contrast,
+            hasher: self.hasher,
+            salt: self.salt,
+            alpha_range: self.alpha_range,
+        }
+    }
+
+    /// Set a named hue family.
+    ///
+    /// Instead of specifying hue ranges and saturation/lightness vectors by
+    /// hand, pick one of the [`Color`] families. The hue is then taken from the
+    /// family's range and the saturation/brightness from its valid polygon.
+    ///
+    /// This is meant to be combined with [`Self::luminosity`] and takes
+    /// precedence over [`Self::hue_range`]/[`Self::hue_ranges`] when set.
+    ///
+    /// See also [`Self::new`] and [`Self::luminosity`].
+    pub fn color_family(self, color: Color) -> Self {
+        Self {
+            s: self.s,
+            l: self.l,
+            hue_ranges: self.hue_ranges,
+            color_family: Some(color),
+            luminosity: self.luminosity,
+            contrast: self.contrast,
+            hasher: self.hasher,
+            salt: self.salt,
+            alpha_range: self.alpha_range,
+        }
+    }
+
+    /// Set a luminosity preset.
+    ///
+    /// This clamps the brightness of a [`Self::color_family`] color into a
+    /// sub-band: `Dark` picks the lower third, `Light` the upper third,
+    /// `Bright` favours a high saturation with mid brightness, and `Random`
+    /// leaves the family's full range untouched. It has no effect unless a
+    /// color family is also set.
+    ///
+    /// See also [`Self::new`] and [`Self::color_family`].
+    pub fn luminosity(self, luminosity: Luminosity) -> Self {
+        Self {
+            s: self.s,
+            l: self.l,
+            hue_ranges: self.hue_ranges,
+            color_family: self.color_family,
+            luminosity: Some(luminosity),
+            contrast: self.contrast,
+            hasher: self.hasher,
+            salt: self.salt,
+            alpha_range: self.alpha_range,
+        }
+    }
+
+    /// Constrain the generated color to meet a WCAG contrast ratio against a
+    /// background.
+    ///
+    /// The hashed hue and saturation are kept, but the HSL lightness is moved
+    /// towards black or white — whichever raises contrast against `bg` — until
+    /// the ratio clears `min_ratio`. This makes the color safe to use as text
+    /// on the given background.
+    ///
+    /// See also [`Self::new`].
+    pub fn contrast_against(self, bg: Rgb, min_ratio: f64) -> Self {
+        Self {
+            s: self.s,
+            l: self.l,
+            hue_ranges: self.hue_ranges,
+            color_family: self.color_family,
+            luminosity: self.luminosity,
+            contrast: Some((bg, min_ratio)),
+            hasher: self.hasher,
+            salt: self.salt,
+            alpha_range: self.alpha_range,
+        }
+    }
+
+    /// Set a custom hash backend.
+    ///
+    /// By default colors are derived from a SHA256 digest, but you can supply
+    /// any [`Hasher`] — for example a faster non-cryptographic hash. Each color
+    /// dimension reads an independent lane from the digest, so at least 20
+    /// bytes are recommended.
+    ///
+    /// See also [`Self::new`] and [`Self::salt`].
+    pub fn hasher(self, hasher: Arc<dyn Hasher>) -> Self {
+        Self {
+            s: self.s,
+            l: self.l,
+            hue_ranges: self.hue_ranges,
+            color_family: self.color_family,
+            luminosity: self.luminosity,
+            contrast: self.contrast,
+            hasher,
+            salt: self.salt,
+            alpha_range: self.alpha_range,
+        }
+    }
+
+    /// Set a salt (namespace) prepended to every input before hashing.
+    ///
+    /// This shifts the whole color space, letting different parts of an
+    /// application derive independent colors from the same strings without
+    /// colliding.
+    ///
+    /// See also [`Self::new`] and [`Self::hasher`].
+    pub fn salt(self, salt: &str) -> Self {
+        Self {
+            s: self.s,
+            l: self.l,
+            hue_ranges: self.hue_ranges,
+            color_family: self.color_family,
+            luminosity: self.luminosity,
+            contrast: self.contrast,
+            hasher: self.hasher,
+            salt: Some(salt.to_owned()),
+            alpha_range: self.alpha_range,
+        }
+    }
+
+    /// Derive a deterministic alpha from the hash.
+    ///
+    /// Without this the generated colors are fully opaque. With an
+    /// `alpha_range`, the alpha is read from its own digest lane and scaled into
+    /// `min..max` (both in `[0, 1]`), so the transparency is as reproducible as
+    /// the color itself.
+    ///
+    /// See also [`Self::to_css_rgb`], [`Self::to_css_hsl`] and [`Self::hexa`].
+    pub fn alpha_range(self, alpha_range: Range<f64>) -> Self {
+        Self {
+            s: self.s,
+            l: self.l,
+            hue_ranges: self.hue_ranges,
+            color_family: self.color_family,
+            luminosity: self.luminosity,
+            contrast: self.contrast,
+            hasher: self.hasher,
+            salt: self.salt,
+            alpha_range: Some(alpha_range),
+        }
+    }
+
+    /// Return the digest of `input`, with the configured salt prepended.
+    fn digest(&self, input: &str) -> Vec<u8> {
+        match &self.salt {
+            Some(salt) => self.hasher.digest(&format!("{salt}{input}")),
+            None => self.hasher.digest(input),
         }
     }
 
@@ -133,21 +576,164 @@ impl ColorHash {
     ///
     /// Note that H ∈ [0, 360); S ∈ [0, 100]; L ∈ [0, 100];
     pub fn hsl(&self, input: &str) -> Hsl {
-        let hash = rgb_hash(input);
+        let digest = self.digest(input);
         let hue_resolution = 727; // note that 727 is a prime
 
-        let h = if self.hue_ranges.len() > 0 {
-            let range = &self.hue_ranges[hash % self.hue_ranges.len()];
-            ((hash / self.hue_ranges.len()) % hue_resolution) as f64 * (range.end - range.start)
+        if let Some(color) = self.color_family {
+            return self.apply_contrast(self.color_family_hsl(&digest, color));
+        }
+
+        // Each dimension reads its own lane from the digest so that hue,
+        // saturation and lightness are statistically independent even when a
+        // high-resolution sub-hue is in play.
+        let h = if !self.hue_ranges.is_empty() {
+            let range = &self.hue_ranges[lane(&digest, 0) % self.hue_ranges.len()];
+            (lane(&digest, 1) % hue_resolution) as f64 * (range.end - range.start)
                 / hue_resolution as f64
                 + range.start
         } else {
-            (hash % 359) as f64 // note that 359 is a prime
+            (lane(&digest, 0) % 359) as f64 // note that 359 is a prime
+        };
+        let s = self.s[lane(&digest, 2) % self.s.len()];
+        let l = self.l[lane(&digest, 3) % self.l.len()];
+
+        self.apply_contrast(Hsl::new(h, s, l, None))
+    }
+
+    /// Derive an HSL color from a named hue family and the current luminosity.
+    ///
+    /// The hash is split into three independent lanes (hue, saturation,
+    /// brightness), each taken modulo a prime for an even distribution, and the
+    /// resulting HSB triple is converted to HSL.
+    fn color_family_hsl(&self, digest: &[u8], color: Color) -> Hsl {
+        let hue_resolution = 727; // note that 727 is a prime
+        let sat_resolution = 127; // note that 127 is a prime
+        let bri_resolution = 131; // note that 131 is a prime
+
+        let h_lane = (lane(digest, 0) % hue_resolution) as f64 / hue_resolution as f64;
+        let s_lane = (lane(digest, 1) % sat_resolution) as f64 / sat_resolution as f64;
+        let b_lane = (lane(digest, 2) % bri_resolution) as f64 / bri_resolution as f64;
+
+        let (hue_start, hue_end) = color.hue_range();
+        let mut hue = hue_start + h_lane * (hue_end - hue_start);
+        if hue < 0.0 {
+            hue += 360.0;
+        }
+
+        let (mut s_min, s_max) = color.saturation_range();
+        let saturation = if color == Color::Monochrome {
+            0.0
+        } else {
+            if self.luminosity == Some(Luminosity::Bright) {
+                s_min = s_min.max(55.0).min(s_max);
+            }
+            s_min + s_lane * (s_max - s_min)
         };
-        let s = self.s[(hash / 360) % self.s.len()];
-        let l = self.l[(hash / 360 / self.s.len()) % self.l.len()];
 
-        Hsl::new(h as f64, s, l, None)
+        let min_brightness = color.minimum_brightness(saturation);
+        let (mut b_min, mut b_max) = (min_brightness, 100.0);
+        let span = b_max - b_min;
+        match self.luminosity {
+            // "Dark" is the lower third of the brightness scale. It uses an
+            // absolute band rather than `min_brightness + span/3`: at the
+            // family's minimum saturation the lower-bound polygon balloons to
+            // brightness 100, which would collapse the sub-band and make a
+            // "dark" swatch nearly white.
+            Some(Luminosity::Dark) => {
+                b_min = 0.0;
+                b_max = 100.0 / 3.0;
+            }
+            Some(Luminosity::Light) => b_min += 2.0 * span / 3.0,
+            Some(Luminosity::Bright) => {
+                b_min = min_brightness + span / 3.0;
+                b_max = min_brightness + 2.0 * span / 3.0;
+            }
+            Some(Luminosity::Random) | None => {}
+        }
+        let brightness = b_min + b_lane * (b_max - b_min);
+
+        hsb_to_hsl(hue, saturation, brightness)
+    }
+
+    /// Adjust a color's lightness so it meets the configured contrast ratio
+    /// against the background, if any. Returns the color unchanged when no
+    /// contrast constraint is set.
+    fn apply_contrast(&self, color: Hsl) -> Hsl {
+        let (bg, min_ratio) = match &self.contrast {
+            Some(contrast) => contrast,
+            None => return color,
+        };
+        let bg_luminance = relative_luminance(bg);
+        let (hue, saturation, start) = (color.hue(), color.saturation(), color.lightness());
+
+        let ratio_at = |lightness: f64| {
+            let rgb: Rgb = Hsl::new(hue, saturation, lightness, None).into();
+            contrast_ratio(relative_luminance(&rgb), bg_luminance)
+        };
+
+        if ratio_at(start) >= *min_ratio {
+            return color;
+        }
+
+        // Move towards whichever extreme raises contrast fastest.
+        let target = if ratio_at(0.0) >= ratio_at(100.0) {
+            0.0
+        } else {
+            100.0
+        };
+        if ratio_at(target) < *min_ratio {
+            return Hsl::new(hue, saturation, target, None);
+        }
+
+        // Contrast grows monotonically from `start` towards `target`, so binary
+        // search for the nearest compliant lightness.
+        let (mut lo, mut hi) = (start, target);
+        for _ in 0..40 {
+            let mid = (lo + hi) / 2.0;
+            if ratio_at(mid) >= *min_ratio {
+                hi = mid;
+            } else {
+                lo = mid;
+            }
+        }
+        Hsl::new(hue, saturation, hi, None)
+    }
+
+    /// Returns `n` visually distinct colors derived deterministically from a
+    /// single string.
+    ///
+    /// The first swatch is the full single color for `input` (so
+    /// `palette(input, 1)` equals `[hsl(input)]`), honouring every builder
+    /// option including [`Self::color_family`] and [`Self::contrast_against`].
+    /// Each successive hue is then advanced by the golden-ratio increment around
+    /// the hue circle so the colors are maximally spread, with saturation and
+    /// lightness cycling through the configured vectors indexed by the step
+    /// number.
+    ///
+    /// Because the spread deliberately walks the whole circle, swatches past
+    /// index 0 are **not** confined to a [`Self::color_family`] — that would
+    /// collapse the spread back into a single hue range. A
+    /// [`Self::contrast_against`] constraint, however, is still applied to every
+    /// swatch so the whole palette stays legible on the background.
+    ///
+    /// This is handy for assigning a stable set of category colors (e.g. a
+    /// username and its sub-labels) that must all be distinguishable yet
+    /// reproducible.
+    pub fn palette(&self, input: &str, n: usize) -> Vec<Hsl> {
+        let base = self.hsl(input);
+        let mut hue = base.hue();
+        let mut out = Vec::with_capacity(n);
+        for step in 0..n {
+            if step == 0 {
+                out.push(base.clone());
+                continue;
+            }
+            hue = (hue + 360.0 * 0.618_033_988_75) % 360.0;
+            let s = self.s[step % self.s.len()];
+            let l = self.l[step % self.l.len()];
+            out.push(self.apply_contrast(Hsl::new(hue, s, l, None)));
+        }
+        out
     }
 
     /// Returns the hash in RGB.
@@ -157,11 +743,84 @@ impl ColorHash {
         self.hsl(input).into()
     }
 
+    /// The deterministic alpha for `input`, in `[0, 1]`.
+    ///
+    /// Returns `1.0` (fully opaque) unless an [`Self::alpha_range`] is set.
+    fn alpha(&self, input: &str) -> f64 {
+        match &self.alpha_range {
+            Some(range) => {
+                let resolution = 727; // note that 727 is a prime
+                let fraction = (lane(&self.digest(input), 4) % resolution) as f64
+                    / (resolution - 1) as f64;
+                range.start + fraction * (range.end - range.start)
+            }
+            None => 1.0,
+        }
+    }
+
     /// Returns the hash in HTML-style hex string.
     ///
+    /// Emits `#RRGGBB`, or the 8-digit `#RRGGBBAA` form when an
+    /// [`Self::alpha_range`] makes the color translucent. A fully opaque color
+    /// keeps the classic six-digit form.
+    ///
     /// You could also generate CSS style RGB string using `rgb(input).to_css_string().`
     pub fn hex(&self, input: &str) -> String {
-        self.rgb(input).to_hex_string()
+        let alpha = self.alpha(input);
+        if alpha >= 1.0 {
+            self.rgb(input).to_hex_string()
+        } else {
+            self.hexa(input)
+        }
+    }
+
+    /// Returns the hash as an 8-digit `#RRGGBBAA` hex string.
+    ///
+    /// Unlike [`Self::hex`] the alpha byte is always present, even when the
+    /// color is fully opaque.
+    pub fn hexa(&self, input: &str) -> String {
+        let rgb = self.rgb(input);
+        let byte = |c: f64| c.round() as u8;
+        format!(
+            "#{:02x}{:02x}{:02x}{:02x}",
+            byte(rgb.red()),
+            byte(rgb.green()),
+            byte(rgb.blue()),
+            byte(self.alpha(input) * 255.0),
+        )
+    }
+
+    /// Returns a CSS `rgb(...)` / `rgba(...)` string.
+    ///
+    /// The alpha component is omitted (and the `rgb()` form used) when the color
+    /// is fully opaque.
+    pub fn to_css_rgb(&self, input: &str) -> String {
+        let rgb = self.rgb(input);
+        let byte = |c: f64| c.round() as u8;
+        let (r, g, b) = (byte(rgb.red()), byte(rgb.green()), byte(rgb.blue()));
+        let alpha = self.alpha(input);
+        if alpha >= 1.0 {
+            format!("rgb({}, {}, {})", r, g, b)
+        } else {
+            format!("rgba({}, {}, {}, {})", r, g, b, format_alpha(alpha))
+        }
+    }
+
+    /// Returns a CSS `hsl(...)` / `hsla(...)` string.
+    ///
+    /// The hue is normalised into `[0, 360)` and the alpha component is omitted
+    /// (and the `hsl()` form used) when the color is fully opaque.
+    pub fn to_css_hsl(&self, input: &str) -> String {
+        let hsl = self.hsl(input);
+        let h = normalize_hue(hsl.hue());
+        let s = hsl.saturation();
+        let l = hsl.lightness();
+        let alpha = self.alpha(input);
+        if alpha >= 1.0 {
+            format!("hsl({}, {}%, {}%)", h, s, l)
+        } else {
+            format!("hsla({}, {}%, {}%, {})", h, s, l, format_alpha(alpha))
+        }
     }
 }
 
@@ -171,19 +830,21 @@ mod tests {
 
     #[test]
     fn hashing() {
-        assert_eq!(rgb_hash("hello world"), 3108841401);
-        assert_eq!(rgb_hash("a"), 3398926610);
-        assert_eq!(rgb_hash("b"), 1042540566);
-        assert_eq!(rgb_hash("c"), 779955203);
+        // The first big-endian lane matches the historical `rgb_hash` vectors.
+        let sha = Sha256Hasher;
+        assert_eq!(lane(&sha.digest("hello world"), 0), 3108841401);
+        assert_eq!(lane(&sha.digest("a"), 0), 3398926610);
+        assert_eq!(lane(&sha.digest("b"), 0), 1042540566);
+        assert_eq!(lane(&sha.digest("c"), 0), 779955203);
     }
 
     #[test]
     fn hsl_colors() {
         let ch = ColorHash::new();
-        assert_eq!(ch.hsl("hello world"), Hsl::new(126.0, 65., 65., None));
-        assert_eq!(ch.hsl("a"), Hsl::new(52.0, 35., 50., None));
-        assert_eq!(ch.hsl("b"), Hsl::new(258.0, 50., 65., None));
-        assert_eq!(ch.hsl("c"), Hsl::new(60.0, 65., 65., None));
+        assert_eq!(ch.hsl("hello world"), Hsl::new(126.0, 50., 65., None));
+        assert_eq!(ch.hsl("a"), Hsl::new(52.0, 35., 35., None));
+        assert_eq!(ch.hsl("b"), Hsl::new(258.0, 65., 65., None));
+        assert_eq!(ch.hsl("c"), Hsl::new(60.0, 50., 65., None));
     }
 
     #[test]
@@ -248,4 +909,120 @@ mod tests {
             assert!([90.0, 100.0].contains(&hsl.lightness()));
         }
     }
+
+    #[test]
+    fn palette_of_one_equals_single_color() {
+        let ch = ColorHash::new();
+        assert_eq!(ch.palette("hello world", 1), vec![ch.hsl("hello world")]);
+    }
+
+    #[test]
+    fn palette_spreads_hues_by_the_golden_ratio() {
+        let ch = ColorHash::new();
+        let palette = ch.palette("hello world", 5);
+        assert_eq!(palette.len(), 5);
+        let expected = (126.0 + 360.0 * 0.618_033_988_75) % 360.0;
+        assert_float_eq!(palette[1].hue(), expected, abs_all <= 0.001);
+    }
+
+    #[test]
+    fn contrast_against_background_clears_the_requested_ratio() {
+        let white = Rgb::new(255.0, 255.0, 255.0, None);
+        let hash = ColorHash::new().contrast_against(white.clone(), 4.5);
+        let bg_luminance = relative_luminance(&white);
+        for _ in 0..100 {
+            let rgb = hash.rgb(&nanoid!());
+            let ratio = contrast_ratio(relative_luminance(&rgb), bg_luminance);
+            assert!(ratio >= 4.5 - 0.01);
+        }
+    }
+
+    #[test]
+    fn salt_shifts_the_color_space() {
+        let plain = ColorHash::new();
+        let salted = ColorHash::new().salt("namespace:");
+        // The same input maps to a different color once a salt is applied,
+        // while the salted space stays deterministic.
+        assert_ne!(plain.hsl("hello world"), salted.hsl("hello world"));
+        assert_eq!(salted.hsl("hello world"), salted.hsl("hello world"));
+    }
+
+    #[test]
+    fn custom_hasher_drives_the_color() {
+        // A hasher returning a fixed digest collapses every input to one color.
+        #[derive(Debug)]
+        struct Fixed;
+        impl Hasher for Fixed {
+            fn digest(&self, _input: &str) -> Vec<u8> {
+                vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]
+            }
+        }
+        let ch = ColorHash::new().hasher(Arc::new(Fixed));
+        assert_eq!(ch.hsl("a"), ch.hsl("b"));
+    }
+
+    #[test]
+    fn opaque_colors_omit_the_alpha_component() {
+        let ch = ColorHash::new();
+        assert!(ch.to_css_rgb("hello world").starts_with("rgb("));
+        assert!(ch.to_css_hsl("hello world").starts_with("hsl("));
+        assert_eq!(ch.hex("hello world").len(), 7); // #RRGGBB
+    }
+
+    #[test]
+    fn alpha_range_produces_translucent_serializations() {
+        let ch = ColorHash::new().alpha_range(0.0..0.5);
+        assert!(ch.to_css_rgb("hello world").starts_with("rgba("));
+        assert!(ch.to_css_hsl("hello world").starts_with("hsla("));
+        assert_eq!(ch.hex("hello world").len(), 9); // #RRGGBBAA
+        // `hexa` always carries the alpha byte, even for an opaque color.
+        assert_eq!(ColorHash::new().hexa("hello world").len(), 9);
+        // The alpha is as deterministic as the color itself.
+        assert_eq!(ch.hexa("hello world"), ch.hexa("hello world"));
+    }
+
+    #[test]
+    fn css_hue_is_normalized_into_the_unit_circle() {
+        assert_float_eq!(normalize_hue(380.0), 20.0, abs_all <= 0.001);
+        assert_float_eq!(normalize_hue(-10.0), 350.0, abs_all <= 0.001);
+        assert_float_eq!(normalize_hue(360.0), 0.0, abs_all <= 0.001);
+    }
+
+    #[test]
+    fn alpha_falls_back_to_three_decimals_when_two_do_not_round_trip() {
+        assert_eq!(format_alpha(0.5), "0.5");
+        assert_eq!(format_alpha(1.0), "1");
+        // 1/255 rounds to byte 1, which two decimals (0.00) cannot represent.
+        assert_eq!(format_alpha(1.0 / 255.0), "0.004");
+    }
+
+    #[test]
+    fn should_return_color_within_the_named_family_hue_range() {
+        let hash = ColorHash::new().color_family(Color::Blue);
+        for _ in 0..100 {
+            let hue = hash.hsl(&nanoid!()).hue();
+            assert!((179.0..=257.0).contains(&hue));
+        }
+    }
+
+    #[test]
+    fn monochrome_family_is_always_desaturated() {
+        let hash = ColorHash::new().color_family(Color::Monochrome);
+        for _ in 0..100 {
+            assert_float_eq!(hash.hsl(&nanoid!()).saturation(), 0.0, abs_all <= 0.001);
+        }
+    }
+
+    #[test]
+    fn dark_luminosity_stays_in_the_lower_brightness_band() {
+        let hash = ColorHash::new()
+            .color_family(Color::Red)
+            .luminosity(Luminosity::Dark);
+        for _ in 0..100 {
+            // A dark sub-band stays firmly in the lower brightness range for
+            // every saturation, including the family's minimum where the
+            // lower-bound polygon would otherwise force a near-white swatch.
+            assert!(hash.hsl(&nanoid!()).lightness() < 60.0);
+        }
+    }
 }